@@ -0,0 +1,100 @@
+use interm::render::{self, Update, Writer};
+use interm::{interactive::Line as InteractiveLine, Block};
+use std::io::{Error, Result as IoResult};
+use std::process::exit;
+use std::time::Duration;
+use tokio::time::sleep;
+
+fn generate_interactive_elements<const T: usize>() -> Vec<InteractiveLine> {
+    let mut elements: Vec<InteractiveLine> = Vec::with_capacity(T);
+    let names = generate_names::<T>();
+    for name in names.iter() {
+        elements.push(InteractiveLine::new(name));
+    }
+    elements
+}
+
+fn generate_names<const T: usize>() -> Vec<String> {
+    let mut names = Vec::with_capacity(T);
+
+    for idx in 0..T {
+        names.push(format!("Download {idx}"));
+    }
+
+    names
+}
+
+// Unlike `download_sync`, the worker below never touches the `Block` directly: it only holds a
+// cheap `Writer` clone, so there is no lock to drop before `sleep` and no lock to block its
+// siblings on.
+async fn download(writer: Writer, line_id: u8, content: String) -> IoResult<()> {
+    let r = rand::random::<u8>();
+
+    for i in 0..=100 {
+        let progress = (i as f64) / 100.0;
+        let mut progress_bar = "=".repeat((progress * 49.0) as usize);
+        progress_bar.push('>');
+        let rendered = format!(
+            "{content}: [{progress_bar:<50}] {progress:.1}%",
+            progress = progress * 100.0,
+        );
+
+        writer.send(Update::Write {
+            line_id,
+            content: rendered,
+            clear: true,
+        })?;
+        sleep(Duration::from_millis(100 * (r / 100) as u64)).await;
+    }
+
+    writer.send(Update::Write {
+        line_id,
+        content: format!("\x1b[34m{content}: Complete\x1b[0m"),
+        clear: true,
+    })?;
+
+    Ok(())
+}
+
+async fn try_main() -> Result<(), Error> {
+    let elements = generate_interactive_elements::<10>();
+    let mut block = Block::new(elements)?;
+    let (writer, reader) = render::channel();
+
+    block.hide_cursor()?;
+
+    let mut downloads = Vec::with_capacity(block.interactive_lines.len());
+    for (line_id, elem) in block.interactive_lines.iter().enumerate() {
+        let writer = writer.clone();
+        downloads.push(tokio::spawn(download(
+            writer,
+            line_id as u8,
+            elem.content.clone(),
+        )));
+    }
+    drop(writer);
+
+    // `Block` holds `Cell`s for its cursor bookkeeping and so isn't `Sync`; the render task that
+    // owns it runs on the local task set instead of being handed to the (`Send`-only) thread pool.
+    let render_task = tokio::task::spawn_local(async move { block.render_loop(reader).await });
+
+    for download in downloads {
+        download.await??;
+    }
+    render_task.await??;
+
+    println!("\x1b[36mAll downloads complete!\x1b[0m");
+    Ok(())
+}
+
+#[tokio::main]
+async fn main() {
+    let local = tokio::task::LocalSet::new();
+    match local.run_until(try_main()).await {
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            exit(1);
+        }
+    }
+}
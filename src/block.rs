@@ -1,19 +1,89 @@
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::io::{self, stdout, Error, Result as IoResult, Write};
+use std::time::{Duration, Instant};
 
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+use crate::event::Event;
+use crate::history::History;
 use crate::interactive::Line as InteractiveLine;
+use crate::render::{Reader, Update};
+use crate::resize;
 
 /// Block is a struct that represents a block of interactive elements or indexed lines in the terminal. It consists of a vector of [`InteractiveElement`] instances.
 pub struct Block {
     pub interactive_lines: Vec<InteractiveLine>,
     pub cursor_position: Position,
+    history: History,
+    /// The bytes currently visible on each row, used to diff against new content in
+    /// [`Block::update_element`] so only the changed suffix is rewritten.
+    shadow: Vec<String>,
+    /// ANSI writes queued by [`Block::write_inline`]/[`Block::write_raw`] since the last
+    /// [`Block::flush`], so a batch of per-line writes costs one `write_all` instead of one per
+    /// write.
+    pending: RefCell<String>,
+    /// Lines currently animated by [`render::Writer::spinner`], keyed by `line_id`. Only
+    /// populated and advanced from [`Block::render_loop`]; the sync API has no spinner support
+    /// since it has no background task to drive one.
+    spinners: HashMap<u8, SpinnerState>,
+}
+
+/// Per-line animation state for a spinner registered via [`render::Writer::spinner`]: the frames
+/// it cycles through, which one is currently showing, and the content it's prefixed onto.
+struct SpinnerState {
+    frames: Vec<String>,
+    frame_idx: usize,
+    content: String,
 }
 
+/// Default window used to coalesce rapid successive [`Block::update_element`] calls into a
+/// single undo step, the way Helix coalesces keystrokes.
+const DEFAULT_COALESCE_WINDOW: Duration = Duration::from_millis(500);
+
 #[allow(unused)]
 pub struct Position {
-    row: Cell<u8>,
+    row: Cell<u16>,
     col: Cell<u8>,
+    cols: Cell<u16>,
+    rows: Cell<u16>,
+}
+
+impl Position {
+    /// The terminal's current column count, used to work out how many physical rows a line
+    /// occupies once it wraps.
+    pub fn cols(&self) -> u16 {
+        self.cols.get()
+    }
+
+    /// The terminal's current row count.
+    pub fn rows(&self) -> u16 {
+        self.rows.get()
+    }
+}
+
+/// Counts the columns `content` actually occupies once printed, skipping over ANSI CSI escape
+/// sequences (`\x1b[...letter`, e.g. the `\x1b[34m`/`\x1b[0m` color codes `download_channel`'s
+/// "Complete" message uses) instead of counting their bytes as visible characters. Without this,
+/// a styled line's `chars().count()` could cross a `cols` boundary its on-screen text never does,
+/// corrupting every `physical_row_of` sum after it.
+fn visible_width(content: &str) -> u16 {
+    let mut width = 0u16;
+    let mut chars = content.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            chars.next();
+            for c in chars.by_ref() {
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            continue;
+        }
+        width += 1;
+    }
+    width
 }
 
 impl Block {
@@ -52,10 +122,13 @@ impl Block {
             "interactive_lines vector is empty"
         );
 
+        let (cols, rows) = resize::terminal_size().unwrap_or((80, 24));
         let mut lines_vec: Vec<InteractiveLine> = Vec::with_capacity(interactive_lines.len());
         let cursor_position = Position {
-            row: Cell::new(interactive_lines.len() as u8),
+            row: Cell::new(0),
             col: Cell::new(0),
+            cols: Cell::new(cols),
+            rows: Cell::new(rows),
         };
 
         for (idx, line) in interactive_lines.into_iter().enumerate() {
@@ -64,16 +137,109 @@ impl Block {
             lines_vec.push(line);
         }
 
-        let cur = Self {
+        let shadow = vec![String::new(); lines_vec.len()];
+        let mut cur = Self {
             cursor_position,
             interactive_lines: lines_vec,
+            history: History::new(DEFAULT_COALESCE_WINDOW),
+            shadow,
+            pending: RefCell::new(String::new()),
+            spinners: HashMap::new(),
         };
 
+        cur.cursor_position
+            .row
+            .set(cur.physical_row_of(cur.interactive_lines.len() as u8));
         cur.prepare_all()?;
 
+        let snapshot = cur.snapshot();
+        cur.history.commit(snapshot, Instant::now());
+
         Ok(cur)
     }
 
+    /// Applies a terminal [`Event`] to the block. Currently only [`Event::Resize`] is handled:
+    /// the occupied row count is recomputed for the new width, the reserved blank space is
+    /// re-run, and every line is fully repainted from its stored `content` so wrapped lines no
+    /// longer corrupt the block's row bookkeeping.
+    /// # Errors
+    /// Returns an error if a write to stdout fails.
+    pub fn apply_event(&mut self, event: Event) -> IoResult<()> {
+        match event {
+            Event::Resize { cols, rows } => self.handle_resize(cols, rows),
+        }
+    }
+
+    fn handle_resize(&mut self, cols: u16, rows: u16) -> IoResult<()> {
+        self.move_up(self.cursor_position.row.get())?;
+        self.write_inline("\x1b[J")?;
+        self.invalidate_shadow();
+
+        self.cursor_position.cols.set(cols);
+        self.cursor_position.rows.set(rows);
+        self.cursor_position.row.set(0);
+
+        self.prepare_all()?;
+        self.cursor_position
+            .row
+            .set(self.physical_row_of(self.interactive_lines.len() as u8));
+
+        for idx in 0..self.interactive_lines.len() {
+            self.goto_idx(idx)?;
+            let content = self.interactive_lines[idx].content.clone();
+            self.write_diff(idx, &content, true)?;
+        }
+        self.flush()
+    }
+
+    /// `tokio::io::AsyncWrite`-backed equivalent of [`Block::handle_resize`], driven by
+    /// [`Update::Resize`] instead of [`Event::Resize`] so resizing composes with
+    /// [`Block::render_loop`]: once a worker holds only a [`render::Writer`], `apply_event` is no
+    /// longer reachable because `Block` has moved into the render task.
+    async fn handle_resize_async<W: AsyncWrite + Unpin>(
+        &mut self,
+        out: &mut W,
+        cols: u16,
+        rows: u16,
+    ) -> IoResult<()> {
+        self.move_up(self.cursor_position.row.get())?;
+        self.write_inline("\x1b[J")?;
+        self.invalidate_shadow();
+
+        self.cursor_position.cols.set(cols);
+        self.cursor_position.rows.set(rows);
+        self.cursor_position.row.set(0);
+
+        self.prepare_all_buffered()?;
+        self.cursor_position
+            .row
+            .set(self.physical_row_of(self.interactive_lines.len() as u8));
+
+        for idx in 0..self.interactive_lines.len() {
+            self.goto_idx(idx)?;
+            let content = self.interactive_lines[idx].content.clone();
+            self.write_diff(idx, &content, true)?;
+        }
+        self.flush_async(out).await
+    }
+
+    /// Number of physical terminal rows a line holding `content` will occupy given the current terminal
+    /// width: `ceil(width / cols)`, rounded up to at least one row.
+    fn line_physical_rows(&self, content: &str) -> u16 {
+        let cols = self.cursor_position.cols.get().max(1);
+        let width = visible_width(content);
+        width.div_ceil(cols).max(1)
+    }
+
+    /// Sum of physical rows occupied by the lines before `idx`, i.e. the physical row `idx`
+    /// starts on.
+    fn physical_row_of(&self, idx: u8) -> u16 {
+        self.interactive_lines[..idx as usize]
+            .iter()
+            .map(|line| self.line_physical_rows(&line.content))
+            .sum()
+    }
+
     /// Updates the content of `elem` which is an `InteractiveElement` instance. Optionally, you can clear the line before updating.
     /// # Example
     /// ```rust
@@ -99,6 +265,12 @@ impl Block {
     /// [`Block::hide_cursor()`](struct.Block.html#method.hide_cursor)
     /// [`Block::show_cursor()`](struct.Block.html#method.show_cursor)
     ///
+    ///
+    /// # Notes
+    /// `content` is diffed against the bytes last written to `elem`'s row: only the changed
+    /// suffix is rewritten instead of clearing and redrawing the whole line. Pass `clear: true`
+    /// if `content` may be shorter than what's currently on screen, so the leftover tail gets
+    /// erased.
     pub fn update_element(
         &mut self,
         elem: &InteractiveLine,
@@ -116,11 +288,12 @@ impl Block {
         }
 
         self.goto_element(elem)?;
-        if clear {
-            self.clear_line()?;
-        }
+        self.write_diff(relative_row as usize, content, clear)?;
+        self.flush()?;
+
+        let snapshot = self.snapshot();
+        self.history.commit(snapshot, Instant::now());
 
-        self.write_inline(content)?;
         Ok(())
     }
 
@@ -152,7 +325,9 @@ impl Block {
     pub fn goto_idx(&self, idx: usize) -> Result<(), Error> {
         if let Some(el) = self.interactive_lines.get(idx) {
             self.go_to(el)?;
-            self.cursor_position.row.set(el.relative_row.get());
+            self.cursor_position
+                .row
+                .set(self.physical_row_of(el.relative_row.get()));
         } else {
             return Err(Error::new(
                 io::ErrorKind::Other,
@@ -192,7 +367,7 @@ impl Block {
         let relative_row = el.relative_row.get();
         if let Some(el) = self.interactive_lines.get(relative_row as usize) {
             self.go_to(el)?;
-            self.cursor_position.row.set(relative_row);
+            self.cursor_position.row.set(self.physical_row_of(relative_row));
         } else {
             return Err(Error::new(
                 io::ErrorKind::Other,
@@ -222,6 +397,11 @@ impl Block {
     /// [`Block::hide_cursor()`](struct.Block.html#method.hide_cursor)
     /// [`Block::show_cursor()`](struct.Block.html#method.show_cursor)
     pub fn clear_line(&self) -> IoResult<()> {
+        self.clear_line_buffered()?;
+        self.flush()
+    }
+
+    fn clear_line_buffered(&self) -> IoResult<()> {
         self.write_inline("\x1b[2K\r")?;
         Ok(())
     }
@@ -244,14 +424,23 @@ impl Block {
     /// [`Block::clear_line()`](struct.Block.html#method.clear_line)
     /// [`Block::hide_cursor()`](struct.Block.html#method.hide_cursor)
     /// [`Block::show_cursor()`](struct.Block.html#method.show_cursor)
+    ///
+    /// # Notes
+    /// Every line's clear is queued and [`Block::flush`] is called once at the end, so clearing
+    /// N lines costs a single `write_all` instead of N. [`Block::write_diff`]'s cache is reset too,
+    /// so a later [`Block::update_element`] that happens to repaint a row with its pre-clear
+    /// content still writes it out instead of mistaking it for a no-op.
     pub fn clear_lines(&mut self) -> IoResult<()> {
         let last_line = self.interactive_lines.len() - 1;
         self.goto_idx(last_line)?;
-        for _ in 0..last_line {
-            self.clear_line()?;
-            self.move_up(1)?;
+        for idx in (1..=last_line).rev() {
+            self.clear_line_buffered()?;
+            let rows_above = self.line_physical_rows(&self.interactive_lines[idx - 1].content);
+            self.move_up(rows_above)?;
         }
-        Ok(())
+        self.clear_line_buffered()?;
+        self.invalidate_shadow();
+        self.flush()
     }
 
     /// Hides cursor.
@@ -274,7 +463,7 @@ impl Block {
     /// [`Block::show_cursor()`](struct.Block.html#method.show_cursor)
     pub fn hide_cursor(&self) -> IoResult<()> {
         self.write_inline("\x1b[?25l")?;
-        Ok(())
+        self.flush()
     }
 
     /// Shows cursor.
@@ -301,28 +490,224 @@ impl Block {
     /// If you want to show the cursor before the `Block` instance is dropped, you can call this method.
     pub fn show_cursor(&self) -> IoResult<()> {
         self.write_inline("\x1b[?25h")?;
+        self.flush()
+    }
+
+    /// Overrides the window used to coalesce rapid successive [`Block::update_element`] calls
+    /// into a single undo step. Defaults to 500ms.
+    /// # See also
+    /// [`Block::undo()`](struct.Block.html#method.undo)
+    /// [`Block::redo()`](struct.Block.html#method.redo)
+    pub fn configure_history(&mut self, coalesce_window: Duration) {
+        self.history.set_coalesce_window(coalesce_window);
+    }
+
+    /// Restores every line to its content as of the previous revision and repaints whichever
+    /// lines changed. [`Block::update_element`] calls made within the coalescing window (see
+    /// [`Block::configure_history`]) share one revision, so a single undo steps back over all of
+    /// them. Does nothing if there is no earlier revision.
+    /// # Example
+    /// ```rust
+    /// use interm::{interactive::Line as InteractiveLine, Block};
+    ///
+    /// let elements = vec![InteractiveLine::new("Download 0")];
+    /// let mut block = Block::new(elements).unwrap();
+    ///
+    /// let elem = block.interactive_lines[0].clone();
+    /// block.update_element(&elem, "Download 0: Complete", true).unwrap();
+    /// block.undo().unwrap(); // back to "Download 0"
+    /// ```
+    /// # Errors
+    /// Returns an error if a write to stdout fails.
+    /// # See also
+    /// [`Block::redo()`](struct.Block.html#method.redo)
+    /// [`Block::revert_to()`](struct.Block.html#method.revert_to)
+    pub fn undo(&mut self) -> IoResult<()> {
+        if let Some(lines) = self.history.undo().map(<[String]>::to_vec) {
+            self.restore(&lines)?;
+        }
+        Ok(())
+    }
+
+    /// Restores every line to its content as of one [`Block::undo`] ago and repaints whichever
+    /// lines changed. Does nothing if already at the newest revision.
+    /// # Errors
+    /// Returns an error if a write to stdout fails.
+    /// # See also
+    /// [`Block::undo()`](struct.Block.html#method.undo)
+    pub fn redo(&mut self) -> IoResult<()> {
+        if let Some(lines) = self.history.redo().map(<[String]>::to_vec) {
+            self.restore(&lines)?;
+        }
         Ok(())
     }
 
+    /// Restores every line to the revision recorded closest to `at` and repaints whichever
+    /// lines changed.
+    /// # Errors
+    /// Returns an error if a write to stdout fails.
+    /// # See also
+    /// [`Block::undo()`](struct.Block.html#method.undo)
+    /// [`Block::redo()`](struct.Block.html#method.redo)
+    pub fn revert_to(&mut self, at: Instant) -> IoResult<()> {
+        if let Some(lines) = self.history.revert_to(at).map(<[String]>::to_vec) {
+            self.restore(&lines)?;
+        }
+        Ok(())
+    }
+
+    fn snapshot(&self) -> Vec<String> {
+        self.interactive_lines
+            .iter()
+            .map(|line| line.content.clone())
+            .collect()
+    }
+
+    fn restore(&mut self, lines: &[String]) -> IoResult<()> {
+        for (idx, content) in lines.iter().enumerate() {
+            let changed = self
+                .interactive_lines
+                .get(idx)
+                .is_some_and(|line| &line.content != content);
+            if !changed {
+                continue;
+            }
+
+            if let Some(elem) = self.interactive_lines.get_mut(idx) {
+                elem.content = content.clone();
+            }
+            self.goto_idx(idx)?;
+            self.write_diff(idx, content, true)?;
+        }
+        self.flush()
+    }
+
+    /// Queues `str` (bracketed with carriage returns, matching every other write in this module)
+    /// without touching stdout. Call [`Block::flush`] to actually write it out.
     fn write_inline(&self, str: &str) -> IoResult<()> {
-        {
-            let mut out = stdout().lock();
-            let prepared_str = format!("\r{str}\r", str = str);
-            out.write_all(prepared_str.as_bytes())?;
-            out.flush()?;
+        let prepared_str = format!("\r{str}\r", str = str);
+        self.write_raw(&prepared_str)
+    }
+
+    /// Queues `str` verbatim, with no carriage-return bracketing, so callers that need to land on
+    /// an exact column (like [`Block::write_diff`]) aren't reset back to column 0 afterwards.
+    fn write_raw(&self, str: &str) -> IoResult<()> {
+        self.pending.borrow_mut().push_str(str);
+        Ok(())
+    }
+
+    /// Writes every write queued since the last flush to stdout in a single `write_all`, so a
+    /// batch of per-line updates costs one syscall instead of one per line.
+    /// # Errors
+    /// Returns an error if the write to stdout fails.
+    pub fn flush(&self) -> IoResult<()> {
+        let mut pending = self.pending.borrow_mut();
+        if pending.is_empty() {
+            return Ok(());
+        }
+        let mut out = stdout().lock();
+        out.write_all(pending.as_bytes())?;
+        out.flush()?;
+        pending.clear();
+        Ok(())
+    }
+
+    /// Truncates `content` to at most [`Position::cols`] visible columns: visible characters past
+    /// that are dropped, not wrapped onto another row. Embedded ANSI escape sequences are copied
+    /// through untouched (and uncounted, consistent with [`visible_width`]) so a styled suffix
+    /// like a trailing reset code still reaches the terminal even once the visible text ahead of
+    /// it has been cut off. Content this method doesn't see (raw cursor-movement
+    /// sequences written via [`Block::write_inline`] directly) isn't clamped, since it was never
+    /// meant to be measured against the terminal width in the first place.
+    fn clamp_to_cols(&self, content: &str) -> String {
+        let cols = self.cursor_position.cols() as usize;
+        if cols == 0 {
+            return content.to_string();
+        }
+
+        let mut out = String::with_capacity(content.len());
+        let mut visible = 0usize;
+        let mut chars = content.chars().peekable();
+        while let Some(c) = chars.next() {
+            if c == '\x1b' && chars.peek() == Some(&'[') {
+                out.push(c);
+                out.push(chars.next().expect("peeked Some above"));
+                for c in chars.by_ref() {
+                    out.push(c);
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+                continue;
+            }
+            if visible >= cols {
+                continue;
+            }
+            out.push(c);
+            visible += 1;
+        }
+        out
+    }
+
+    /// Diffs `content` (truncated to [`Position::cols`] via [`Block::clamp_to_cols`] first)
+    /// against the bytes last flushed for row `idx` and queues only the changed suffix: a
+    /// `\x1b[{col}G` cursor-column move to the first differing character followed by the rest of
+    /// `content`. If `clear_trailing` is set and `content` is visibly shorter than what was there before,
+    /// a trailing `\x1b[K` erases the leftover tail instead of falling back to a full `\x1b[2K`
+    /// line clear.
+    fn write_diff(&mut self, idx: usize, content: &str, clear_trailing: bool) -> IoResult<()> {
+        let content = self.clamp_to_cols(content);
+        let content = content.as_str();
+        let prev = self.shadow.get(idx).cloned().unwrap_or_default();
+        let common_chars = prev
+            .chars()
+            .zip(content.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let mut common_bytes: usize = content.chars().take(common_chars).map(char::len_utf8).sum();
+        // If the common prefix stops inside an escape sequence, rewrite from its `\x1b` instead.
+        if let Some(esc) = content[..common_bytes].rfind('\x1b') {
+            let complete = content[esc..common_bytes]
+                .chars()
+                .skip(2)
+                .any(|c| c.is_ascii_alphabetic());
+            if !complete {
+                common_bytes = esc;
+            }
+        }
+        let col = visible_width(&content[..common_bytes]) + 1;
+
+        self.write_raw(&format!("\x1b[{col}G"))?;
+        self.write_raw(&content[common_bytes..])?;
+
+        if clear_trailing && visible_width(content) < visible_width(&prev) {
+            self.write_raw("\x1b[K")?;
+        }
+
+        if let Some(slot) = self.shadow.get_mut(idx) {
+            *slot = content.to_string();
         }
         Ok(())
     }
 
+    /// Resets every row's [`Block::write_diff`] cache to empty, so the next write to each row is
+    /// treated as a full repaint instead of being diffed against bytes the terminal no longer
+    /// has. Call this after any raw `\x1b[2K`/`\x1b[J` clear that bypasses `write_diff` itself —
+    /// otherwise a row whose new content happens to match what `shadow` remembers gets nothing but
+    /// a cursor move queued, leaving it visibly blank on an actually-cleared terminal.
+    fn invalidate_shadow(&mut self) {
+        self.shadow.iter_mut().for_each(|slot| slot.clear());
+    }
+
     fn go_to(&self, el: &InteractiveLine) -> IoResult<()> {
-        let relative_row = el.relative_row.get();
-        match self.cursor_position.row.get().cmp(&relative_row) {
+        let target_row = self.physical_row_of(el.relative_row.get());
+        match self.cursor_position.row.get().cmp(&target_row) {
             Ordering::Greater => {
-                let move_by = self.cursor_position.row.get().abs_diff(relative_row);
+                let move_by = self.cursor_position.row.get().abs_diff(target_row);
                 self.move_up(move_by)?;
             }
             Ordering::Less => {
-                let move_by = relative_row.abs_diff(self.cursor_position.row.get());
+                let move_by = target_row.abs_diff(self.cursor_position.row.get());
                 self.move_down(move_by)?;
             }
             _ => {}
@@ -330,21 +715,211 @@ impl Block {
         Ok(())
     }
 
-    fn move_up(&self, n: u8) -> IoResult<()> {
+    fn move_up(&self, n: u16) -> IoResult<()> {
         let up_seq = format!("\x1b[{n}F");
         self.write_inline(&up_seq)?;
         Ok(())
     }
 
-    fn move_down(&self, n: u8) -> IoResult<()> {
+    fn move_down(&self, n: u16) -> IoResult<()> {
         let down_seq = format!("\x1b[{n}E");
         self.write_inline(&down_seq)?;
         Ok(())
     }
 
     fn prepare_all(&self) -> IoResult<()> {
-        let prepared_space = "\n".repeat(self.interactive_lines.len());
-        self.write_inline(&prepared_space)?;
+        self.prepare_all_buffered()?;
+        self.flush()
+    }
+
+    fn prepare_all_buffered(&self) -> IoResult<()> {
+        let total_rows = self.physical_row_of(self.interactive_lines.len() as u8);
+        let prepared_space = "\n".repeat(total_rows as usize);
+        self.write_inline(&prepared_space)
+    }
+
+    /// Owns stdout for the lifetime of the loop, applying [`Update`]s sent by worker tasks that
+    /// only hold a [`render::Writer`] clone. Because a single task performs every write, no lock
+    /// is ever held across an `.await`: workers update their progress and sleep freely instead of
+    /// blocking each other on an `Arc<TokioMutex<Block>>`.
+    ///
+    /// Spinners registered with [`render::Writer::spinner`] live here too: `Update::Tick`,
+    /// broadcast by a single [`render::spawn_ticker`] task shared across every spinner, advances
+    /// and repaints whichever lines are currently animating.
+    ///
+    /// Resizes arrive the same way: `Update::Resize`, sent by
+    /// [`resize::spawn_resize_forwarder`], is this loop's equivalent of [`Block::apply_event`]
+    /// for a `Block` that has already been moved into this task.
+    /// # Example
+    /// ```rust
+    /// use interm::{interactive::Line as InteractiveLine, render, Block};
+    ///
+    /// # async fn run() -> std::io::Result<()> {
+    /// let mut block = Block::new(vec![InteractiveLine::new("Download 0")])?;
+    /// let (writer, reader) = render::channel();
+    ///
+    /// tokio::task::spawn_local(async move { block.render_loop(reader).await });
+    /// writer.send(render::Update::Write {
+    ///     line_id: 0,
+    ///     content: "done".to_string(),
+    ///     clear: true,
+    /// })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    /// # Errors
+    /// Returns an error if a write to stdout fails.
+    ///
+    /// # Notes
+    /// `Block` keeps its cursor bookkeeping in `Cell`s and so is not `Sync`. Run this method with
+    /// `tokio::task::spawn_local` inside a `LocalSet` rather than `tokio::spawn`.
+    ///
+    /// # See also
+    /// [`render::channel()`](render/fn.channel.html)
+    pub async fn render_loop(&mut self, mut reader: Reader) -> IoResult<()> {
+        let mut stdout = tokio::io::stdout();
+        while let Some(update) = reader.0.recv().await {
+            match update {
+                Update::Write {
+                    line_id,
+                    content,
+                    clear,
+                } => {
+                    self.update_element_async(&mut stdout, line_id, &content, clear)
+                        .await?;
+                }
+                Update::Goto { line_id } => {
+                    self.goto_idx_async(&mut stdout, line_id).await?;
+                }
+                Update::Clear => {
+                    self.clear_lines_async(&mut stdout).await?;
+                }
+                Update::StartSpinner {
+                    line_id,
+                    frames,
+                    content,
+                } => {
+                    self.spinners.insert(
+                        line_id,
+                        SpinnerState {
+                            frames,
+                            frame_idx: 0,
+                            content,
+                        },
+                    );
+                }
+                Update::Tick => {
+                    self.tick_spinners(&mut stdout).await?;
+                }
+                Update::FinishSpinner { line_id, content } => {
+                    self.spinners.remove(&line_id);
+                    self.update_element_async(&mut stdout, line_id, &content, true)
+                        .await?;
+                }
+                Update::Resize { cols, rows } => {
+                    self.handle_resize_async(&mut stdout, cols, rows).await?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Advances every spinner registered via [`render::Writer::spinner`] to its next frame and
+    /// repaints it through [`Block::update_element_async`], the same path a worker's `Update::Write`
+    /// takes. A spinner with no frames is skipped rather than animated: [`render::Writer::spinner`]
+    /// already rejects empty `frames`, but `Update::StartSpinner`'s fields are public, so this
+    /// stays defensive against one built by hand.
+    async fn tick_spinners<W: AsyncWrite + Unpin>(&mut self, out: &mut W) -> IoResult<()> {
+        let line_ids: Vec<u8> = self.spinners.keys().copied().collect();
+        for line_id in line_ids {
+            let rendered = {
+                let state = self
+                    .spinners
+                    .get_mut(&line_id)
+                    .expect("line_id was just collected from self.spinners");
+                if state.frames.is_empty() {
+                    continue;
+                }
+                state.frame_idx = (state.frame_idx + 1) % state.frames.len();
+                format!("{} {}", state.frames[state.frame_idx], state.content)
+            };
+            self.update_element_async(out, line_id, &rendered, true)
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// `tokio::io::AsyncWrite`-backed equivalent of [`Block::update_element`], used internally by
+    /// [`Block::render_loop`] so the render task never blocks on the sync stdout lock. Like the
+    /// sync version, the write is diffed against `shadow` via [`Block::write_diff`] and queued
+    /// writes are flushed to `out` in a single batch.
+    async fn update_element_async<W: AsyncWrite + Unpin>(
+        &mut self,
+        out: &mut W,
+        line_id: u8,
+        content: &str,
+        clear: bool,
+    ) -> IoResult<()> {
+        if let Some(elem) = self.interactive_lines.get_mut(line_id as usize) {
+            elem.content = content.to_string();
+        } else {
+            return Err(Error::new(
+                io::ErrorKind::Other,
+                format!("index {line_id} not found"),
+            ));
+        }
+
+        self.goto_idx(line_id as usize)?;
+        self.write_inline("\r")?;
+        self.write_diff(line_id as usize, content, clear)?;
+        self.flush_async(out).await?;
+
+        let snapshot = self.snapshot();
+        self.history.commit(snapshot, Instant::now());
+
+        Ok(())
+    }
+
+    /// `tokio::io::AsyncWrite`-backed equivalent of [`Block::goto_idx`], flushed to `out` once.
+    async fn goto_idx_async<W: AsyncWrite + Unpin>(
+        &self,
+        out: &mut W,
+        line_id: u8,
+    ) -> IoResult<()> {
+        self.goto_idx(line_id as usize)?;
+        self.write_inline("\r")?;
+        self.flush_async(out).await
+    }
+
+    /// `tokio::io::AsyncWrite`-backed equivalent of [`Block::clear_lines`]: every line's clear is
+    /// queued and flushed to `out` once, the same batching [`Block::clear_lines`] gives the sync
+    /// API.
+    async fn clear_lines_async<W: AsyncWrite + Unpin>(&mut self, out: &mut W) -> IoResult<()> {
+        let last_line = self.interactive_lines.len() - 1;
+        self.goto_idx(last_line)?;
+        self.write_inline("\r")?;
+        for idx in (1..=last_line).rev() {
+            self.clear_line_buffered()?;
+            let rows_above = self.line_physical_rows(&self.interactive_lines[idx - 1].content);
+            self.move_up(rows_above)?;
+        }
+        self.clear_line_buffered()?;
+        self.invalidate_shadow();
+        self.flush_async(out).await
+    }
+
+    /// `tokio::io::AsyncWrite`-backed equivalent of [`Block::flush`]: writes every write queued
+    /// since the last flush to `out` in a single `write_all`, so a batch of per-line updates
+    /// through [`Block::render_loop`] costs one syscall instead of one per line.
+    async fn flush_async<W: AsyncWrite + Unpin>(&self, out: &mut W) -> IoResult<()> {
+        // Taken out of the `RefCell` (rather than borrowed across the writes below) so the
+        // borrow doesn't live across an `.await` point.
+        let pending = std::mem::take(&mut *self.pending.borrow_mut());
+        if pending.is_empty() {
+            return Ok(());
+        }
+        out.write_all(pending.as_bytes()).await?;
+        out.flush().await?;
         Ok(())
     }
 }
@@ -355,3 +930,67 @@ impl Drop for Block {
         self.show_cursor().unwrap();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_block(n: usize) -> Block {
+        let elements = (0..n)
+            .map(|idx| InteractiveLine::new(format!("line{idx}").as_str()))
+            .collect();
+        Block::new(elements).unwrap()
+    }
+
+    #[tokio::test]
+    async fn handle_resize_async_reinvalidates_shadow_before_repaint() {
+        let mut block = test_block(1);
+        let elem = block.interactive_lines[0].clone();
+        block.update_element(&elem, "hello", true).unwrap();
+
+        // Content is unchanged across the resize, so without invalidating `shadow` first,
+        // `write_diff` would see it as a no-op and queue nothing but a cursor move, leaving the
+        // row blank on the physically-cleared terminal.
+        let mut out = Vec::new();
+        block.handle_resize_async(&mut out, 80, 24).await.unwrap();
+        assert!(String::from_utf8(out).unwrap().contains("hello"));
+    }
+
+    #[test]
+    fn write_diff_skips_unchanged_content() {
+        let mut block = test_block(1);
+        block.pending.borrow_mut().clear();
+
+        block.write_diff(0, "hello", true).unwrap();
+        assert!(block.pending.borrow().contains("hello"));
+        block.pending.borrow_mut().clear();
+
+        block.write_diff(0, "hello", true).unwrap();
+        assert!(!block.pending.borrow().contains("hello"));
+    }
+
+    #[test]
+    fn invalidate_shadow_forces_full_repaint() {
+        let mut block = test_block(1);
+        block.write_diff(0, "hello", true).unwrap();
+        block.pending.borrow_mut().clear();
+
+        block.invalidate_shadow();
+        block.write_diff(0, "hello", true).unwrap();
+        assert!(block.pending.borrow().contains("hello"));
+    }
+
+    #[test]
+    fn clear_lines_invalidates_shadow() {
+        let mut block = test_block(2);
+        block.write_diff(0, "hello", true).unwrap();
+        block.write_diff(1, "world", true).unwrap();
+
+        block.clear_lines().unwrap();
+        assert!(block.shadow.iter().all(String::is_empty));
+
+        block.pending.borrow_mut().clear();
+        block.write_diff(0, "hello", true).unwrap();
+        assert!(block.pending.borrow().contains("hello"));
+    }
+}
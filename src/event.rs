@@ -0,0 +1,9 @@
+/// Terminal events that a [`Block`] can react to, fed in from a listener task rather than being
+/// polled for directly by the caller.
+///
+/// [`Block`]: struct.Block.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Event {
+    /// The terminal was resized to `cols` columns and `rows` rows.
+    Resize { cols: u16, rows: u16 },
+}
@@ -0,0 +1,126 @@
+use std::time::{Duration, Instant};
+
+/// A single committed snapshot of every line's content.
+#[derive(Debug, Clone)]
+struct Revision {
+    lines: Vec<String>,
+    at: Instant,
+}
+
+/// Linear revision history of a block's line contents, modeled on Helix's `History`: each
+/// commit is addressed by index, [`History::undo`]/[`History::redo`] step one revision at a time,
+/// and [`History::earlier`]/[`History::later`] step over every revision committed within a given
+/// span of time. Commits made within `coalesce_window` of the current revision are folded into
+/// it instead of growing the history, the way Helix coalesces rapid keystrokes into one undo step.
+pub struct History {
+    revisions: Vec<Revision>,
+    current: usize,
+    coalesce_window: Duration,
+}
+
+impl History {
+    /// Creates an empty history that coalesces commits within `coalesce_window` of each other.
+    pub fn new(coalesce_window: Duration) -> Self {
+        Self {
+            revisions: Vec::new(),
+            current: 0,
+            coalesce_window,
+        }
+    }
+
+    /// Overrides the coalescing window used by future commits.
+    pub fn set_coalesce_window(&mut self, coalesce_window: Duration) {
+        self.coalesce_window = coalesce_window;
+    }
+
+    /// Records `lines` as a new revision stamped `at`, or folds it into the current revision if
+    /// it arrived within the coalescing window. Any redo tail past the current revision is
+    /// discarded, matching the usual undo-after-edit semantics.
+    ///
+    /// The very first commit (from [`Block::new`]) is never coalesced into, even if the next one
+    /// lands inside the window: it's the pre-edit baseline, and folding the first real change
+    /// into it would leave [`Block::undo`] with nowhere to go after a single edit.
+    ///
+    /// [`Block::new`]: struct.Block.html#method.new
+    /// [`Block::undo`]: struct.Block.html#method.undo
+    pub(crate) fn commit(&mut self, lines: Vec<String>, at: Instant) {
+        if self.revisions.is_empty() {
+            self.revisions.push(Revision { lines, at });
+            self.current = 0;
+            return;
+        }
+
+        let past_bootstrap = self.revisions.len() > 1;
+        if past_bootstrap && self.current == self.revisions.len() - 1 {
+            let current = &mut self.revisions[self.current];
+            if at.duration_since(current.at) < self.coalesce_window {
+                current.lines = lines;
+                current.at = at;
+                return;
+            }
+        }
+
+        self.revisions.truncate(self.current + 1);
+        self.revisions.push(Revision { lines, at });
+        self.current = self.revisions.len() - 1;
+    }
+
+    /// Steps back one revision and returns its line contents, or `None` if already at the
+    /// oldest revision.
+    pub fn undo(&mut self) -> Option<&[String]> {
+        if self.current == 0 {
+            return None;
+        }
+        self.current -= 1;
+        Some(&self.revisions[self.current].lines)
+    }
+
+    /// Steps forward one revision and returns its line contents, or `None` if already at the
+    /// newest revision.
+    pub fn redo(&mut self) -> Option<&[String]> {
+        if self.current + 1 >= self.revisions.len() {
+            return None;
+        }
+        self.current += 1;
+        Some(&self.revisions[self.current].lines)
+    }
+
+    /// Steps back through as many revisions as were committed in the `duration` before the
+    /// current one.
+    pub fn earlier(&mut self, duration: Duration) -> Option<&[String]> {
+        let target = self.revisions.get(self.current)?.at.checked_sub(duration)?;
+        while self.current > 0 && self.revisions[self.current - 1].at >= target {
+            self.current -= 1;
+        }
+        Some(&self.revisions[self.current].lines)
+    }
+
+    /// Steps forward through as many revisions as were committed in the `duration` after the
+    /// current one.
+    pub fn later(&mut self, duration: Duration) -> Option<&[String]> {
+        let target = self.revisions.get(self.current)?.at.checked_add(duration)?;
+        while self.current + 1 < self.revisions.len() && self.revisions[self.current + 1].at <= target
+        {
+            self.current += 1;
+        }
+        Some(&self.revisions[self.current].lines)
+    }
+
+    /// Jumps directly to the revision whose timestamp is closest to `at`.
+    pub fn revert_to(&mut self, at: Instant) -> Option<&[String]> {
+        let idx = self
+            .revisions
+            .iter()
+            .enumerate()
+            .min_by_key(|(_, rev)| {
+                if rev.at >= at {
+                    rev.at.duration_since(at)
+                } else {
+                    at.duration_since(rev.at)
+                }
+            })
+            .map(|(idx, _)| idx)?;
+        self.current = idx;
+        Some(&self.revisions[self.current].lines)
+    }
+}
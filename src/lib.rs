@@ -7,3 +7,8 @@ pub mod interactive {
 
     pub use lines::InteractiveLine as Line;
 }
+
+pub mod event;
+pub mod history;
+pub mod render;
+pub mod resize;
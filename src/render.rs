@@ -0,0 +1,176 @@
+use std::io;
+use std::time::Duration;
+
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::task::JoinHandle;
+
+/// Braille frames cycling clockwise, the same set nbsh and most CLI spinners reach for by
+/// default. Pass these to [`Writer::spinner`] when the caller has no opinion of their own.
+pub const BRAILLE_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// A change to apply to the terminal, sent from worker tasks to the task running
+/// [`Block::render_loop`]. Lines are addressed by their index in the `interactive_lines` vector
+/// instead of by reference, since workers only hold a [`Writer`] and never touch the [`Block`].
+///
+/// [`Block`]: struct.Block.html
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+#[derive(Debug, Clone)]
+pub enum Update {
+    /// Move the cursor to `line_id` and rewrite its content; with `clear`, any old content left
+    /// past the end of the new content is erased.
+    Write {
+        line_id: u8,
+        content: String,
+        clear: bool,
+    },
+    /// Move the cursor to `line_id` without writing anything.
+    Goto { line_id: u8 },
+    /// Clear every interactive line in the block.
+    Clear,
+    /// Registers `line_id` as an auto-animated spinner: `frames` cycle through on every
+    /// [`Update::Tick`], each rendered as `"{frame} {content}"`. Sent by [`Writer::spinner`].
+    StartSpinner {
+        line_id: u8,
+        frames: Vec<String>,
+        content: String,
+    },
+    /// Advances every registered spinner to its next frame and repaints it. Sent by the shared
+    /// ticker task spawned with [`spawn_ticker`], not by callers directly.
+    Tick,
+    /// Stops animating `line_id` and leaves `content` in its place. Sent by
+    /// [`SpinnerHandle::finish`].
+    FinishSpinner { line_id: u8, content: String },
+    /// The terminal was resized to `cols` columns and `rows` rows: recompute every line's
+    /// occupied row count and fully repaint. Sent by [`crate::resize::spawn_resize_forwarder`]
+    /// so resize handling composes with [`Block::render_loop`] instead of only the synchronous
+    /// `Block::apply_event` path.
+    ///
+    /// [`Block::render_loop`]: struct.Block.html#method.render_loop
+    Resize { cols: u16, rows: u16 },
+}
+
+/// The sending half of the render channel returned by [`channel()`]. Cheap to clone; hand a clone
+/// to every worker task instead of sharing the [`Block`] behind a lock.
+///
+/// [`Block`]: struct.Block.html
+/// [`channel()`]: fn.channel.html
+#[derive(Debug, Clone)]
+pub struct Writer(UnboundedSender<Update>);
+
+impl Writer {
+    /// Sends `update` to the task running [`Block::render_loop`].
+    /// # Errors
+    /// Returns an error if the render task has already stopped.
+    ///
+    /// [`Block::render_loop`]: struct.Block.html#method.render_loop
+    pub fn send(&self, update: Update) -> io::Result<()> {
+        self.0
+            .send(update)
+            .map_err(|e| io::Error::new(io::ErrorKind::BrokenPipe, e.to_string()))
+    }
+
+    /// Registers `line_id` as an auto-animated spinner cycling through `frames`, prefixed onto
+    /// `content`, every time a ticker spawned with [`spawn_ticker`] ticks. Returns a handle whose
+    /// [`SpinnerHandle::finish`] stops the animation and leaves a final value in its place.
+    /// # Errors
+    /// Returns an error if the render task has already stopped, or if `frames` is empty (there
+    /// would be nothing to cycle through).
+    pub fn spinner(
+        &self,
+        line_id: u8,
+        frames: impl IntoIterator<Item = impl Into<String>>,
+        content: impl Into<String>,
+    ) -> io::Result<SpinnerHandle> {
+        let frames: Vec<String> = frames.into_iter().map(Into::into).collect();
+        if frames.is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "spinner frames must not be empty",
+            ));
+        }
+        self.send(Update::StartSpinner {
+            line_id,
+            frames,
+            content: content.into(),
+        })?;
+        Ok(SpinnerHandle {
+            line_id,
+            writer: self.clone(),
+        })
+    }
+}
+
+/// Handle to a spinner registered with [`Writer::spinner`]. Dropping it without calling
+/// [`SpinnerHandle::finish`] leaves the line animating forever, since [`Block::render_loop`] has
+/// no other way to learn the work it was animating is done.
+///
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+pub struct SpinnerHandle {
+    line_id: u8,
+    writer: Writer,
+}
+
+impl SpinnerHandle {
+    /// Stops the spinner and writes `content` in its place.
+    /// # Errors
+    /// Returns an error if the render task has already stopped.
+    pub fn finish(self, content: impl Into<String>) -> io::Result<()> {
+        self.writer.send(Update::FinishSpinner {
+            line_id: self.line_id,
+            content: content.into(),
+        })
+    }
+}
+
+/// Spawns the single background task that drives every spinner registered via
+/// [`Writer::spinner`]: it sends an [`Update::Tick`] on `writer` every `interval`, and
+/// [`Block::render_loop`] advances and repaints whichever lines are currently spinning. One
+/// ticker serves any number of spinners, so animating ten lines costs the same one timer as
+/// animating one.
+/// # Example
+/// ```rust
+/// use interm::render;
+/// use std::time::Duration;
+///
+/// # fn run() {
+/// let (writer, _reader) = render::channel();
+/// render::spawn_ticker(writer.clone(), Duration::from_millis(80));
+/// let spinner = writer.spinner(0, render::BRAILLE_FRAMES, "Downloading").unwrap();
+/// spinner.finish("Done").unwrap();
+/// # }
+/// ```
+///
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+pub fn spawn_ticker(writer: Writer, interval: Duration) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if writer.send(Update::Tick).is_err() {
+                break;
+            }
+        }
+    })
+}
+
+/// The receiving half of the render channel returned by [`channel()`], consumed by
+/// [`Block::render_loop`].
+///
+/// [`channel()`]: fn.channel.html
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+pub struct Reader(pub(crate) UnboundedReceiver<Update>);
+
+/// Creates a linked [`Writer`]/[`Reader`] pair backed by a `tokio::sync::mpsc::unbounded_channel`.
+/// Pass the [`Reader`] to [`Block::render_loop`] and clone the [`Writer`] into every worker task.
+/// # Example
+/// ```rust
+/// use interm::render;
+///
+/// let (writer, reader) = render::channel();
+/// ```
+///
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+pub fn channel() -> (Writer, Reader) {
+    let (tx, rx) = mpsc::unbounded_channel();
+    (Writer(tx), Reader(rx))
+}
@@ -0,0 +1,65 @@
+use std::io;
+
+use tokio::signal::unix::{signal, SignalKind};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+use tokio::task::JoinHandle;
+
+use crate::event::Event;
+use crate::render::{Update, Writer};
+
+/// Queries the current terminal size via `ioctl(TIOCGWINSZ)` on stdout.
+/// # Errors
+/// Returns an error if the `ioctl` call fails, e.g. because stdout is not a terminal.
+pub fn terminal_size() -> io::Result<(u16, u16)> {
+    let mut winsize: libc::winsize = unsafe { std::mem::zeroed() };
+    let ret = unsafe { libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut winsize) };
+    if ret != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok((winsize.ws_col, winsize.ws_row))
+}
+
+/// Spawns a task that listens for `SIGWINCH` and emits an [`Event::Resize`] on an unbounded
+/// channel each time the terminal's dimensions change. Mirrors the resize-as-event approach nbsh
+/// takes for its own `Event::Resize((u16, u16))`, rather than having callers poll the terminal
+/// size themselves.
+/// # Errors
+/// Returns an error if installing the `SIGWINCH` handler fails.
+pub fn spawn_listener() -> io::Result<UnboundedReceiver<Event>> {
+    let mut sigwinch = signal(SignalKind::window_change())?;
+    let (tx, rx) = mpsc::unbounded_channel();
+
+    tokio::spawn(async move {
+        while sigwinch.recv().await.is_some() {
+            if let Ok((cols, rows)) = terminal_size() {
+                if tx.send(Event::Resize { cols, rows }).is_err() {
+                    break;
+                }
+            }
+        }
+    });
+
+    Ok(rx)
+}
+
+/// Spawns a task that listens for `SIGWINCH` and sends an [`Update::Resize`] on `writer` each
+/// time the terminal's dimensions change, the [`Block::render_loop`] equivalent of
+/// [`spawn_listener`]. Use this instead when `Block` has been moved into a `render_loop` task and
+/// `apply_event`/`Event::Resize` is no longer reachable.
+/// # Errors
+/// Returns an error if installing the `SIGWINCH` handler fails.
+///
+/// [`Block::render_loop`]: struct.Block.html#method.render_loop
+pub fn spawn_resize_forwarder(writer: Writer) -> io::Result<JoinHandle<()>> {
+    let mut sigwinch = signal(SignalKind::window_change())?;
+
+    Ok(tokio::spawn(async move {
+        while sigwinch.recv().await.is_some() {
+            if let Ok((cols, rows)) = terminal_size() {
+                if writer.send(Update::Resize { cols, rows }).is_err() {
+                    break;
+                }
+            }
+        }
+    }))
+}